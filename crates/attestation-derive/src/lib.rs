@@ -1,12 +1,18 @@
 //! Procedural macro for automatically deriving ABIProvider implementations
 //!
 //! This crate provides a `#[derive(Attestation)]` macro that automatically generates
-//! the `ABIProvider` trait implementation for Cairo structs, extracting field information
-//! and providing serialization capabilities.
+//! the `ABIProvider` trait implementation for Cairo structs and enums, extracting field
+//! (or variant) information and providing serialization, deserialization, human-readable
+//! calldata parsing, and a machine-readable JSON ABI artifact for off-chain tooling.
+//! Fields whose type is itself a `#[derive(Attestation)]` struct or enum are resolved
+//! recursively.
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
+use sha3::{Digest, Keccak256};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, MetaNameValue, Token, Type};
 
 /// Derive macro for automatically implementing ABIProvider
 ///
@@ -19,7 +25,12 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
 ///     pub field3: u64,
 /// }
 /// ```
-#[proc_macro_derive(Attestation)]
+///
+/// Recognizes `#[attestation_meta(...)]` (attached by the `attestation_abi`
+/// attribute macro, carrying its `name`/`version` args through to the
+/// generated `get_abi()`/`get_version()`) and `#[abi_field(description =
+/// "...")]` on individual fields or enum variants.
+#[proc_macro_derive(Attestation, attributes(attestation_meta, abi_field))]
 pub fn derive_attestation(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -30,67 +41,145 @@ pub fn derive_attestation(input: TokenStream) -> TokenStream {
 }
 
 fn generate_abi_provider(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    match &input.data {
+        Data::Struct(data_struct) => generate_struct_abi_provider(input, data_struct),
+        Data::Enum(data_enum) => generate_enum_abi_provider(input, data_enum),
+        _ => Err(syn::Error::new_spanned(
+            input,
+            "Only structs and enums are supported",
+        )),
+    }
+}
+
+fn generate_struct_abi_provider(
+    input: &DeriveInput,
+    data_struct: &syn::DataStruct,
+) -> syn::Result<proc_macro2::TokenStream> {
     let struct_name = &input.ident;
     let struct_name_str = struct_name.to_string();
 
-    let fields = match &input.data {
-        Data::Struct(data_struct) => match &data_struct.fields {
-            Fields::Named(fields_named) => &fields_named.named,
-            _ => return Err(syn::Error::new_spanned(
+    let fields = match &data_struct.fields {
+        Fields::Named(fields_named) => &fields_named.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
                 input,
-                "Only structs with named fields are supported"
-            )),
-        },
-        _ => return Err(syn::Error::new_spanned(
-            input,
-            "Only structs are supported"
-        )),
+                "Only structs with named fields are supported",
+            ))
+        }
     };
 
     let field_count = fields.len();
 
-    // Generate ABI field definitions
+    let schema = canonical_schema_string(&struct_name_str, fields);
+    let schema_hash = compute_schema_hash(&schema);
+    let schema_hash_literal: proc_macro2::TokenStream = schema_hash
+        .parse()
+        .map_err(|_| syn::Error::new_spanned(input, "failed to parse computed schema hash"))?;
+
+    // `#[attestation_abi(name = "...", version = "...")]` overrides the
+    // display name reported by `get_abi()` and the string `get_version()`
+    // returns; the schema hash itself still hashes the struct as declared,
+    // so a cosmetic rename can't silently change the on-chain layout.
+    let (display_name, version) = get_attestation_meta(input);
+    let display_name = display_name.unwrap_or(struct_name_str.clone());
+    let version = version.unwrap_or_default();
+    let display_name_json = json_escape(&display_name);
+    let version_json = json_escape(&version);
+    let json_prefix = format!(
+        r#"{{"name":"{display_name_json}","schema_hash":"{schema_hash}","version":"{version_json}","fields":["#
+    );
+
+    // Generate ABI field definitions and the runtime total-size accumulation.
+    // Nested attestation types (anything that isn't a recognized primitive)
+    // resolve their own ABI and size at runtime via `ABIProvider::get_abi()`,
+    // so `total_size` can no longer be a macro-expansion-time constant.
     let abi_fields = fields.iter().map(|field| {
         let field_name = field.ident.as_ref().unwrap().to_string();
-        let field_type = &field.ty;
-        let (type_name, size_bytes) = get_type_info(field_type);
+        let description = get_field_description(&field.attrs);
+        generate_field_abi_entry(&field_name, &field.ty, &description)
+    });
 
-        quote! {
-            fields.append(ABIField {
-                name: #field_name,
-                field_type: #type_name,
-                size_bytes: #size_bytes,
-            });
+    // Generate the implementation name
+    let impl_name = syn::Ident::new(&format!("{}ABIProvider", struct_name), struct_name.span());
+
+    // Generate the field-by-field buffer reads used by the deserializer, in
+    // the same order the fields were serialized.
+    let field_deserializers = fields.iter().map(|field| {
+        let field_ident = field.ident.as_ref().unwrap();
+        generate_field_deserializer(field_ident, &field.ty)
+    });
+    let field_idents = fields.iter().map(|field| field.ident.as_ref().unwrap());
+
+    // Human-readable calldata parsing: one string argument per field, in
+    // declared order. `Array<T>`, `Span<T>`, and nested attestation types
+    // aren't representable as a single literal, so if any field has one of
+    // those types the whole method bails instead of leaving an unbound
+    // field in the struct constructor below.
+    let all_fields_calldata_literal = fields.iter().all(|field| {
+        let (type_name, _) = get_type_info(&field.ty);
+        is_calldata_literal_type(&type_name)
+    });
+    let field_calldata_parsers = fields.iter().map(|field| {
+        let field_ident = field.ident.as_ref().unwrap();
+        generate_field_calldata_parser(field_ident, &field.ty)
+    });
+    let field_idents_for_calldata = fields.iter().map(|field| field.ident.as_ref().unwrap());
+
+    // Machine-readable JSON ABI artifact for off-chain tooling, with a
+    // comma emitted between (but not after) field entries.
+    let json_field_entries = fields.iter().enumerate().map(|(index, field)| {
+        let field_name = field.ident.as_ref().unwrap().to_string();
+        let description = get_field_description(&field.attrs);
+        let entry = generate_field_json_entry(&field_name, &field.ty, &description);
+        if index + 1 < field_count {
+            quote! { #entry json += ","; }
+        } else {
+            entry
         }
     });
 
-    // Calculate total size of fixed-size fields
-    let total_size_calculation = fields.iter().map(|field| {
-        let field_type = &field.ty;
-        let (_, size_bytes) = get_type_info(field_type);
-        size_bytes
-    }).sum::<u32>();
+    let from_calldata_impl = if all_fields_calldata_literal {
+        quote! {
+            fn from_calldata(args: Span<ByteArray>) -> Option<#struct_name> {
+                let mut args = args;
 
-    // Generate the implementation name
-    let impl_name = syn::Ident::new(
-        &format!("{}ABIProvider", struct_name),
-        struct_name.span()
-    );
+                #(#field_calldata_parsers)*
+
+                Option::Some(#struct_name { #(#field_idents_for_calldata),* })
+            }
+        }
+    } else {
+        quote! {
+            fn from_calldata(args: Span<ByteArray>) -> Option<#struct_name> {
+                // At least one field (`Array<T>`/`Span<T>`/a nested
+                // attestation type) isn't representable as a single
+                // human-readable literal, so calldata construction isn't
+                // supported for this type.
+                let _ = args;
+                Option::None
+            }
+        }
+    };
 
     let expanded = quote! {
         impl #impl_name of ABIProvider<#struct_name> {
             fn get_abi() -> StructABI {
                 let mut fields = array![];
+                let mut total_size: u32 = 0;
 
                 #(#abi_fields)*
 
                 StructABI {
-                    name: #struct_name_str,
+                    name: #display_name,
                     fields,
-                    total_size: #total_size_calculation,
+                    total_size,
                 }
             }
 
+            fn get_version() -> ByteArray {
+                #version
+            }
+
             fn get_field_count() -> u32 {
                 #field_count
             }
@@ -100,38 +189,658 @@ fn generate_abi_provider(input: &DeriveInput) -> syn::Result<proc_macro2::TokenS
                 self.serialize(ref serialized);
                 serialized
             }
+
+            fn deserialize_from_array(ref span: Span<felt252>) -> Option<#struct_name> {
+                #(#field_deserializers)*
+
+                Option::Some(#struct_name { #(#field_idents),* })
+            }
+
+            fn get_schema_hash() -> felt252 {
+                #schema_hash_literal
+            }
+
+            #from_calldata_impl
+
+            fn get_abi_json() -> ByteArray {
+                let mut json: ByteArray = #json_prefix;
+
+                #(#json_field_entries)*
+
+                json += format!("],\"total_size\":{}}}", Self::get_abi().total_size);
+                json
+            }
         }
     };
 
     Ok(expanded)
 }
 
-/// Map Cairo types to their string representation and byte size
-fn get_type_info(ty: &Type) -> (&'static str, u32) {
+/// Generate the calldata-parsing code for a single field: pop the next
+/// human-readable argument off `args` and convert it to the field's Cairo
+/// type, inferring the literal's format from its shape (hex prefix, quotes,
+/// digit-only) the same way sncast's `TryInferFormat` does. `Array<T>`,
+/// `Span<T>`, and nested attestation types aren't representable as a single
+/// literal, so they fail the whole parse.
+fn generate_field_calldata_parser(field_ident: &syn::Ident, ty: &Type) -> proc_macro2::TokenStream {
+    let arg_ident = syn::Ident::new(
+        &format!("{}_arg", field_ident),
+        proc_macro2::Span::call_site(),
+    );
+    let (type_name, _) = get_type_info(ty);
+
+    let pop_arg = quote! {
+        let #arg_ident = match args.pop_front() {
+            Option::Some(value) => value,
+            Option::None => { return Option::None; },
+        };
+    };
+
+    let parse = match type_name.as_str() {
+        "felt252" => quote! {
+            let #field_ident = match CalldataParser::parse_felt(#arg_ident) {
+                Option::Some(value) => value,
+                Option::None => { return Option::None; },
+            };
+        },
+        "bool" => quote! {
+            let #field_ident = match CalldataParser::parse_bool(#arg_ident) {
+                Option::Some(value) => value,
+                Option::None => { return Option::None; },
+            };
+        },
+        "ContractAddress" | "u8" | "u16" | "u32" | "u64" => quote! {
+            let #field_ident = match CalldataParser::parse_felt(#arg_ident) {
+                Option::Some(value) => match value.try_into() {
+                    Option::Some(converted) => converted,
+                    Option::None => { return Option::None; },
+                },
+                Option::None => { return Option::None; },
+            };
+        },
+        "u128" => quote! {
+            let #field_ident = match CalldataParser::parse_u256(#arg_ident) {
+                Option::Some(value) => match value.try_into() {
+                    Option::Some(converted) => converted,
+                    Option::None => { return Option::None; },
+                },
+                Option::None => { return Option::None; },
+            };
+        },
+        "u256" => quote! {
+            let #field_ident = match CalldataParser::parse_u256(#arg_ident) {
+                Option::Some(value) => value,
+                Option::None => { return Option::None; },
+            };
+        },
+        "ByteArray" => quote! {
+            let #field_ident = match CalldataParser::parse_byte_array(#arg_ident) {
+                Option::Some(value) => value,
+                Option::None => { return Option::None; },
+            };
+        },
+        // Array<T>, Span<T>, and nested attestation types: not representable
+        // as a single human-readable literal.
+        _ => quote! {
+            return Option::None;
+        },
+    };
+
+    quote! {
+        #pop_arg
+        #parse
+    }
+}
+
+/// Generate ABIProvider for an enum: a one-felt discriminant followed by the
+/// matching variant's payload. Variants may be unit (no payload) or carry
+/// exactly one payload type. When every variant's payload is the same size
+/// the layout has a fixed `total_size`; otherwise it's reported as variable
+/// (`0`), the same convention `ByteArray`/`Array`/`Span` already use.
+fn generate_enum_abi_provider(
+    input: &DeriveInput,
+    data_enum: &syn::DataEnum,
+) -> syn::Result<proc_macro2::TokenStream> {
+    const DISCRIMINANT_SIZE: u32 = 32;
+
+    let enum_name = &input.ident;
+    let enum_name_str = enum_name.to_string();
+    let field_count = data_enum.variants.len();
+
+    // See `generate_struct_abi_provider`: the display name/version are
+    // cosmetic overrides and never affect the schema hash below.
+    let (display_name, version) = get_attestation_meta(input);
+    let display_name = display_name.unwrap_or(enum_name_str.clone());
+    let version = version.unwrap_or_default();
+
+    let mut variant_sizes = Vec::new();
+    let mut variant_descriptors = Vec::new();
+    let mut variant_descriptions = Vec::new();
+    let mut schema_parts = Vec::new();
+    let mut variant_arms = Vec::new();
+    let mut variant_calldata_arms = Vec::new();
+    let mut has_variable_payload = false;
+
+    for (index, variant) in data_enum.variants.iter().enumerate() {
+        let variant_ident = &variant.ident;
+        let variant_name = variant_ident.to_string();
+        let discriminant = proc_macro2::Literal::u32_unsuffixed(index as u32);
+
+        let (payload_type, payload_size) = match &variant.fields {
+            Fields::Unit => ("()".to_string(), 0u32),
+            Fields::Unnamed(fields_unnamed) if fields_unnamed.unnamed.len() == 1 => {
+                get_type_info(&fields_unnamed.unnamed.first().unwrap().ty)
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "Attestation enum variants must be unit or carry exactly one payload type",
+                ))
+            }
+        };
+
+        schema_parts.push(format!("{}:{}", variant_name, payload_type));
+        variant_sizes.push(payload_size);
+        // A payload reported as size `0` is variable (or a nested type whose
+        // size is only known at runtime), not a fixed zero-byte payload.
+        if payload_type != "()" && payload_size == 0 {
+            has_variable_payload = true;
+        }
+
+        let description = get_field_description(&variant.attrs);
+        variant_descriptors.push(quote! {
+            fields.append(ABIField {
+                name: #variant_name,
+                field_type: #payload_type,
+                size_bytes: #payload_size,
+                description: #description,
+                descriptor: FieldDescriptor::None,
+            });
+        });
+        variant_descriptions.push(description);
+
+        variant_arms.push(match &variant.fields {
+            Fields::Unit => quote! {
+                #discriminant => Option::Some(#enum_name::#variant_ident),
+            },
+            Fields::Unnamed(fields_unnamed) => {
+                let payload_ty = &fields_unnamed.unnamed.first().unwrap().ty;
+                let payload_ident = syn::Ident::new("payload", proc_macro2::Span::call_site());
+                let payload_reader = generate_field_deserializer(&payload_ident, payload_ty);
+                quote! {
+                    #discriminant => {
+                        #payload_reader
+                        Option::Some(#enum_name::#variant_ident(payload))
+                    },
+                }
+            }
+            Fields::Named(_) => unreachable!("rejected above"),
+        });
+
+        variant_calldata_arms.push(match &variant.fields {
+            Fields::Unit => quote! {
+                #discriminant => Option::Some(#enum_name::#variant_ident),
+            },
+            Fields::Unnamed(fields_unnamed) => {
+                let payload_ty = &fields_unnamed.unnamed.first().unwrap().ty;
+                let (payload_type_name, _) = get_type_info(payload_ty);
+                if is_calldata_literal_type(&payload_type_name) {
+                    let payload_ident =
+                        syn::Ident::new("payload", proc_macro2::Span::call_site());
+                    let payload_parser = generate_field_calldata_parser(&payload_ident, payload_ty);
+                    quote! {
+                        #discriminant => {
+                            #payload_parser
+                            Option::Some(#enum_name::#variant_ident(payload))
+                        },
+                    }
+                } else {
+                    // `Array<T>`/`Span<T>`/a nested attestation type isn't
+                    // representable as a single literal, so this variant
+                    // can't be constructed from calldata.
+                    quote! {
+                        #discriminant => Option::None,
+                    }
+                }
+            }
+            Fields::Named(_) => unreachable!("rejected above"),
+        });
+    }
+
+    let total_size = if !has_variable_payload
+        && variant_sizes.windows(2).all(|pair| pair[0] == pair[1])
+    {
+        DISCRIMINANT_SIZE + variant_sizes.first().copied().unwrap_or(0)
+    } else {
+        0
+    };
+
+    let schema = format!("{}({})", enum_name_str, schema_parts.join(","));
+    let schema_hash = compute_schema_hash(&schema);
+    let schema_hash_literal: proc_macro2::TokenStream = schema_hash
+        .parse()
+        .map_err(|_| syn::Error::new_spanned(input, "failed to parse computed schema hash"))?;
+
+    // Every variant's name/payload-type/size is already known at
+    // macro-expansion time, so the whole JSON ABI artifact can be baked in
+    // as a single literal (unlike the struct path, which may defer nested
+    // field sizes to runtime).
+    let json_fields = schema_parts
+        .iter()
+        .zip(variant_sizes.iter())
+        .zip(variant_descriptions.iter())
+        .map(|((schema_part, size_bytes), description)| {
+            let (name, field_type) = schema_part.split_once(':').unwrap();
+            let description = json_escape(description);
+            format!(
+                r#"{{"name":"{name}","type":"{field_type}","size_bytes":{size_bytes},"description":"{description}"}}"#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let display_name_json = json_escape(&display_name);
+    let version_json = json_escape(&version);
+    let abi_json = format!(
+        r#"{{"name":"{display_name_json}","schema_hash":"{schema_hash}","version":"{version_json}","fields":[{json_fields}],"total_size":{total_size}}}"#
+    );
+
+    let impl_name = syn::Ident::new(&format!("{}ABIProvider", enum_name), enum_name.span());
+
+    let expanded = quote! {
+        impl #impl_name of ABIProvider<#enum_name> {
+            fn get_abi() -> StructABI {
+                let mut fields = array![];
+
+                #(#variant_descriptors)*
+
+                StructABI {
+                    name: #display_name,
+                    fields,
+                    total_size: #total_size,
+                }
+            }
+
+            fn get_version() -> ByteArray {
+                #version
+            }
+
+            fn get_field_count() -> u32 {
+                #field_count
+            }
+
+            fn serialize_to_array(self: #enum_name) -> Array<felt252> {
+                let mut serialized = array![];
+                self.serialize(ref serialized);
+                serialized
+            }
+
+            fn deserialize_from_array(ref span: Span<felt252>) -> Option<#enum_name> {
+                let discriminant = match span.pop_front() {
+                    Option::Some(value) => *value,
+                    Option::None => { return Option::None; },
+                };
+                // `felt252` only allows `0`/`_` match patterns, so the
+                // discriminant is converted to `u32` before dispatch.
+                let discriminant: u32 = match discriminant.try_into() {
+                    Option::Some(converted) => converted,
+                    Option::None => { return Option::None; },
+                };
+
+                match discriminant {
+                    #(#variant_arms)*
+                    _ => Option::None,
+                }
+            }
+
+            fn get_schema_hash() -> felt252 {
+                #schema_hash_literal
+            }
+
+            fn from_calldata(args: Span<ByteArray>) -> Option<#enum_name> {
+                let mut args = args;
+
+                let discriminant_arg = match args.pop_front() {
+                    Option::Some(value) => value,
+                    Option::None => { return Option::None; },
+                };
+                let discriminant: u32 = match CalldataParser::parse_felt(discriminant_arg) {
+                    Option::Some(value) => match value.try_into() {
+                        Option::Some(converted) => converted,
+                        Option::None => { return Option::None; },
+                    },
+                    Option::None => { return Option::None; },
+                };
+
+                match discriminant {
+                    #(#variant_calldata_arms)*
+                    _ => Option::None,
+                }
+            }
+
+            fn get_abi_json() -> ByteArray {
+                #abi_json
+            }
+        }
+    };
+
+    Ok(expanded)
+}
+
+/// Generate the `get_abi()` entry (and matching `total_size` accumulation)
+/// for a single struct field. Primitive fields contribute a compile-time
+/// known size; anything else is treated as a nested `#[derive(Attestation)]`
+/// type whose own ABI (and size) is resolved at runtime.
+fn generate_field_abi_entry(
+    field_name: &str,
+    ty: &Type,
+    description: &str,
+) -> proc_macro2::TokenStream {
+    let (type_name, size_bytes) = get_type_info(ty);
+
+    if is_primitive_type_name(&type_name) {
+        quote! {
+            total_size += #size_bytes;
+            fields.append(ABIField {
+                name: #field_name,
+                field_type: #type_name,
+                size_bytes: #size_bytes,
+                description: #description,
+                descriptor: FieldDescriptor::None,
+            });
+        }
+    } else {
+        let nested_abi_ident = syn::Ident::new(
+            &format!("{}_abi", field_name),
+            proc_macro2::Span::call_site(),
+        );
+        quote! {
+            let #nested_abi_ident = ABIProvider::<#ty>::get_abi();
+            total_size += #nested_abi_ident.total_size;
+            fields.append(ABIField {
+                name: #field_name,
+                field_type: #type_name,
+                size_bytes: #nested_abi_ident.total_size,
+                description: #description,
+                descriptor: FieldDescriptor::Nested(#nested_abi_ident),
+            });
+        }
+    }
+}
+
+/// Generate the `get_abi_json()` fragment for a single field: a JSON object
+/// literal with `name`, `type`, `size_bytes`, and `description` keys,
+/// appended to a `json: ByteArray` accumulator. Primitive fields are baked
+/// in as a compile-time literal; nested fields interpolate their
+/// runtime-resolved size the same way `generate_field_abi_entry` does for
+/// `get_abi()`.
+fn generate_field_json_entry(
+    field_name: &str,
+    ty: &Type,
+    description: &str,
+) -> proc_macro2::TokenStream {
+    let (type_name, size_bytes) = get_type_info(ty);
+    let description = json_escape(description);
+
+    if is_primitive_type_name(&type_name) {
+        let literal = format!(
+            r#"{{"name":"{field_name}","type":"{type_name}","size_bytes":{size_bytes},"description":"{description}"}}"#
+        );
+        quote! {
+            json += #literal;
+        }
+    } else {
+        let nested_size_ident = syn::Ident::new(
+            &format!("{}_json_size", field_name),
+            proc_macro2::Span::call_site(),
+        );
+        quote! {
+            let #nested_size_ident = ABIProvider::<#ty>::get_abi().total_size;
+            json += format!(
+                "{{\"name\":\"{}\",\"type\":\"{}\",\"size_bytes\":{},\"description\":\"{}\"}}",
+                #field_name, #type_name, #nested_size_ident, #description
+            );
+        }
+    }
+}
+
+/// Whether `type_name` is one of the scalar/variable-size primitives
+/// `get_type_info` recognizes, as opposed to a nested attestation type.
+fn is_primitive_type_name(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "ContractAddress"
+            | "felt252"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "u256"
+            | "bool"
+            | "ByteArray"
+            | "Array"
+            | "Span"
+    )
+}
+
+/// Whether `type_name` can be parsed from a single human-readable calldata
+/// argument by `generate_field_calldata_parser`. Unlike `is_primitive_type_name`,
+/// this excludes `Array`/`Span` (and, by not matching, nested attestation
+/// types): they consume a variable number of argument strings rather than
+/// exactly one, so they aren't representable as a single literal.
+fn is_calldata_literal_type(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "ContractAddress"
+            | "felt252"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "u256"
+            | "bool"
+            | "ByteArray"
+    )
+}
+
+/// Build the canonical schema string `StructName(name1:type1,name2:type2,...)`
+/// used as the preimage for the schema hash, in declared field order.
+fn canonical_schema_string(
+    struct_name: &str,
+    fields: &Punctuated<syn::Field, Token![,]>,
+) -> String {
+    let field_parts: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            let field_name = field.ident.as_ref().unwrap().to_string();
+            let (type_name, _) = get_type_info(&field.ty);
+            format!("{}:{}", field_name, type_name)
+        })
+        .collect();
+
+    format!("{}({})", struct_name, field_parts.join(","))
+}
+
+/// Build the canonical schema string for an enum, the same shape as
+/// `canonical_schema_string` but keyed by `variant_name:payload_type` (a
+/// unit variant's payload type is `()`).
+fn canonical_enum_schema_string(
+    enum_name: &str,
+    variants: &Punctuated<syn::Variant, Token![,]>,
+) -> String {
+    let variant_parts: Vec<String> = variants
+        .iter()
+        .map(|variant| {
+            let variant_name = variant.ident.to_string();
+            let payload_type = match &variant.fields {
+                Fields::Unit => "()".to_string(),
+                Fields::Unnamed(fields_unnamed) if fields_unnamed.unnamed.len() == 1 => {
+                    get_type_info(&fields_unnamed.unnamed.first().unwrap().ty).0
+                }
+                _ => "()".to_string(),
+            };
+            format!("{}:{}", variant_name, payload_type)
+        })
+        .collect();
+
+    format!("{}({})", enum_name, variant_parts.join(","))
+}
+
+/// Hash a canonical schema string the same way `starknet_keccak` does: a
+/// keccak256 digest of the UTF-8 bytes, masked down to 250 bits so it fits in
+/// a `felt252`. Returned as a `0x`-prefixed hex literal.
+fn compute_schema_hash(schema: &str) -> String {
+    let mut hasher = Keccak256::new();
+    hasher.update(schema.as_bytes());
+    let mut digest = hasher.finalize();
+
+    // Clear the top 6 bits (256 - 250) so the value fits in a felt252.
+    digest[0] &= 0x03;
+
+    let hex: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("0x{}", hex)
+}
+
+/// Escape `"` and `\` (and control characters that would otherwise break a
+/// JSON string literal) in a user-supplied value — a `description`, `name`,
+/// or `version` from `attestation_abi`/`abi_field` — before it's embedded in
+/// the `get_abi_json()` output.
+fn json_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Generate the buffer-reader code that reconstructs a single field from the
+/// front of `span`, mirroring the encoding `get_type_info` assumes.
+///
+/// Single-felt scalars (`felt252`, `ContractAddress`, `bool`, `u8..u128`) pop
+/// one felt and convert it; `u256` pops a low/high pair; `ByteArray`,
+/// `Array<T>`, and `Span<T>` delegate to `Serde::deserialize`; anything else
+/// is treated as a nested attestation type and recurses into its own
+/// `ABIProvider::deserialize_from_array`. Any missing felt or failed
+/// conversion short-circuits the whole deserialization with `None`.
+fn generate_field_deserializer(field_ident: &syn::Ident, ty: &Type) -> proc_macro2::TokenStream {
+    let type_name = match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        _ => String::new(),
+    };
+
+    match type_name.as_str() {
+        "felt252" => quote! {
+            let #field_ident = match span.pop_front() {
+                Option::Some(value) => *value,
+                Option::None => { return Option::None; },
+            };
+        },
+        "ContractAddress" | "u8" | "u16" | "u32" | "u64" | "u128" => quote! {
+            let #field_ident = match span.pop_front() {
+                Option::Some(value) => match (*value).try_into() {
+                    Option::Some(converted) => converted,
+                    Option::None => { return Option::None; },
+                },
+                Option::None => { return Option::None; },
+            };
+        },
+        "bool" => quote! {
+            let #field_ident = match span.pop_front() {
+                Option::Some(value) => if *value == 0 {
+                    false
+                } else if *value == 1 {
+                    true
+                } else {
+                    return Option::None;
+                },
+                Option::None => { return Option::None; },
+            };
+        },
+        "u256" => quote! {
+            let #field_ident = {
+                let low = match span.pop_front() {
+                    Option::Some(value) => *value,
+                    Option::None => { return Option::None; },
+                };
+                let high = match span.pop_front() {
+                    Option::Some(value) => *value,
+                    Option::None => { return Option::None; },
+                };
+                u256 {
+                    low: match low.try_into() {
+                        Option::Some(converted) => converted,
+                        Option::None => { return Option::None; },
+                    },
+                    high: match high.try_into() {
+                        Option::Some(converted) => converted,
+                        Option::None => { return Option::None; },
+                    },
+                }
+            };
+        },
+        // ByteArray, Array<T>, Span<T>: let the type's own Serde impl
+        // consume as many felts as it needs.
+        "ByteArray" | "Array" | "Span" => quote! {
+            let #field_ident = match Serde::deserialize(ref span) {
+                Option::Some(value) => value,
+                Option::None => { return Option::None; },
+            };
+        },
+        // Anything else is a nested `#[derive(Attestation)]` type: recurse
+        // into its own generated deserializer.
+        _ => quote! {
+            let #field_ident = match ABIProvider::<#ty>::deserialize_from_array(ref span) {
+                Option::Some(value) => value,
+                Option::None => { return Option::None; },
+            };
+        },
+    }
+}
+
+/// Map Cairo types to their string representation and byte size. A type
+/// that isn't a recognized primitive is assumed to be a nested
+/// `#[derive(Attestation)]` struct or enum; its name is kept (so schemas and
+/// generated ABI field names stay meaningful) but its size is resolved at
+/// runtime via that type's own `ABIProvider::get_abi()`, so it's reported as
+/// `0` here.
+fn get_type_info(ty: &Type) -> (String, u32) {
     match ty {
         Type::Path(type_path) => {
             let path = &type_path.path;
             if let Some(segment) = path.segments.last() {
                 match segment.ident.to_string().as_str() {
-                    "ContractAddress" => ("ContractAddress", 32),
-                    "felt252" => ("felt252", 32),
-                    "u8" => ("u8", 1),
-                    "u16" => ("u16", 2),
-                    "u32" => ("u32", 4),
-                    "u64" => ("u64", 8),
-                    "u128" => ("u128", 16),
-                    "u256" => ("u256", 32),
-                    "bool" => ("bool", 1),
-                    "ByteArray" => ("ByteArray", 0), // Variable size
-                    "Array" => ("Array", 0), // Variable size
-                    "Span" => ("Span", 0), // Variable size
-                    _ => ("unknown", 0),
+                    "ContractAddress" => ("ContractAddress".to_string(), 32),
+                    "felt252" => ("felt252".to_string(), 32),
+                    "u8" => ("u8".to_string(), 1),
+                    "u16" => ("u16".to_string(), 2),
+                    "u32" => ("u32".to_string(), 4),
+                    "u64" => ("u64".to_string(), 8),
+                    "u128" => ("u128".to_string(), 16),
+                    "u256" => ("u256".to_string(), 32),
+                    "bool" => ("bool".to_string(), 1),
+                    "ByteArray" => ("ByteArray".to_string(), 0), // Variable size
+                    "Array" => ("Array".to_string(), 0),         // Variable size
+                    "Span" => ("Span".to_string(), 0),           // Variable size
+                    other => (other.to_string(), 0),             // Nested attestation type
                 }
             } else {
-                ("unknown", 0)
+                ("unknown".to_string(), 0)
             }
-        },
-        _ => ("unknown", 0),
+        }
+        _ => ("unknown".to_string(), 0),
     }
 }
 
@@ -146,19 +855,127 @@ fn get_type_info(ty: &Type) -> (&'static str, u32) {
 ///     pub attester: ContractAddress,
 /// }
 /// ```
+///
+/// `name` and `version` are threaded through to the generated `get_abi()`
+/// (as the reported `StructABI.name`) and `get_version()` via an
+/// `#[attestation_meta(...)]` attribute `#[derive(Attestation)]` picks up
+/// (attribute macros consume the attribute that invoked them, so the args
+/// have to be re-attached under a different name to survive to the next
+/// expansion pass).
+///
+/// When a `schema_hash` is supplied, it is checked against the schema hash
+/// `#[derive(Attestation)]` would compute for the struct as written, so a
+/// field that gets added, removed, reordered, or retyped fails the build
+/// instead of silently changing the on-chain layout.
 #[proc_macro_attribute]
 pub fn attestation_abi(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args: proc_macro2::TokenStream = args.into();
     let input = parse_macro_input!(input as DeriveInput);
 
-    // For now, just pass through - could extend with custom attributes
-    quote! { #input }.into()
+    if let Err(err) = check_schema_hash(args.clone(), &input) {
+        return err.to_compile_error().into();
+    }
+
+    quote! {
+        #[attestation_meta(#args)]
+        #input
+    }
+    .into()
 }
 
-/// Helper macro for creating ABI field with custom metadata
-#[proc_macro]
-pub fn abi_field(input: TokenStream) -> TokenStream {
-    // This could be extended to handle custom field metadata
-    input
+/// Parse a `key = "value"` string argument out of an `attestation_abi`-style
+/// argument list.
+fn find_meta_str(args: proc_macro2::TokenStream, key: &str) -> syn::Result<Option<String>> {
+    let parser = Punctuated::<MetaNameValue, Token![,]>::parse_terminated;
+    let parsed = parser.parse2(args)?;
+
+    Ok(parsed.iter().find_map(|name_value| {
+        if !name_value.path.is_ident(key) {
+            return None;
+        }
+        match &name_value.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => Some(lit_str.value()),
+            _ => None,
+        }
+    }))
+}
+
+/// Read the `name`/`version` args back off the `#[attestation_meta(...)]`
+/// attribute `attestation_abi` left behind, if the struct carries one.
+fn get_attestation_meta(input: &DeriveInput) -> (Option<String>, Option<String>) {
+    let Some(attr) = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("attestation_meta"))
+    else {
+        return (None, None);
+    };
+
+    let Ok(args) = attr.meta.require_list().map(|list| list.tokens.clone()) else {
+        return (None, None);
+    };
+
+    let name = find_meta_str(args.clone(), "name").ok().flatten();
+    let version = find_meta_str(args, "version").ok().flatten();
+    (name, version)
+}
+
+/// Read `#[abi_field(description = "...")]` off a field or enum variant, if
+/// present, defaulting to an empty description.
+fn get_field_description(attrs: &[syn::Attribute]) -> String {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("abi_field"))
+        .and_then(|attr| {
+            attr.meta
+                .require_list()
+                .map(|list| list.tokens.clone())
+                .ok()
+        })
+        .and_then(|args| find_meta_str(args, "description").ok().flatten())
+        .unwrap_or_default()
+}
+
+/// If the `attestation_abi` attribute declares a `schema_hash`, recompute it
+/// from the struct's current fields and error out on a mismatch.
+fn check_schema_hash(args: proc_macro2::TokenStream, input: &DeriveInput) -> syn::Result<()> {
+    let declared_hash = find_meta_str(args, "schema_hash")?;
+
+    let Some(declared_hash) = declared_hash else {
+        return Ok(());
+    };
+
+    let schema = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => {
+                canonical_schema_string(&input.ident.to_string(), &fields_named.named)
+            }
+            // Malformed structs are rejected by `derive_attestation` itself.
+            _ => return Ok(()),
+        },
+        Data::Enum(data_enum) => {
+            canonical_enum_schema_string(&input.ident.to_string(), &data_enum.variants)
+        }
+        _ => return Ok(()),
+    };
+
+    let computed_hash = compute_schema_hash(&schema);
+
+    if !declared_hash.eq_ignore_ascii_case(&computed_hash) {
+        return Err(syn::Error::new_spanned(
+            input,
+            format!(
+                "attestation_abi schema_hash mismatch: declared {} but `{}` now hashes to {} \
+                 (a field was added, removed, reordered, or retyped)",
+                declared_hash, input.ident, computed_hash
+            ),
+        ));
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -208,7 +1025,106 @@ mod tests {
     }
 
     #[test]
-    fn test_unsupported_enum() {
+    fn test_deserialize_generated() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                pub field1: ContractAddress,
+                pub field2: felt252,
+                pub field3: u64,
+            }
+        };
+
+        let result = generate_abi_provider(&input);
+        assert!(result.is_ok());
+
+        let generated = result.unwrap().to_string();
+
+        assert!(generated.contains("deserialize_from_array"));
+        assert!(generated.contains("pop_front"));
+        assert!(generated.contains("TestStruct { field1 , field2 , field3 }"));
+    }
+
+    #[test]
+    fn test_schema_hash_generated_and_deterministic() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                pub field1: ContractAddress,
+                pub field2: felt252,
+                pub field3: u64,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("get_schema_hash"));
+
+        let schema = canonical_schema_string(
+            "TestStruct",
+            match &input.data {
+                Data::Struct(data_struct) => match &data_struct.fields {
+                    Fields::Named(fields_named) => &fields_named.named,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            },
+        );
+        assert_eq!(
+            schema,
+            "TestStruct(field1:ContractAddress,field2:felt252,field3:u64)"
+        );
+        assert_eq!(compute_schema_hash(&schema), compute_schema_hash(&schema));
+    }
+
+    #[test]
+    fn test_schema_hash_changes_when_fields_change() {
+        let a = canonical_schema_string_for(vec![("field1", "felt252")]);
+        let b = canonical_schema_string_for(vec![("field1", "felt252"), ("field2", "u64")]);
+        assert_ne!(compute_schema_hash(&a), compute_schema_hash(&b));
+    }
+
+    fn canonical_schema_string_for(fields: Vec<(&str, &str)>) -> String {
+        let field_parts: Vec<String> = fields
+            .iter()
+            .map(|(name, ty)| format!("{}:{}", name, ty))
+            .collect();
+        format!("S({})", field_parts.join(","))
+    }
+
+    #[test]
+    fn test_attestation_abi_rejects_stale_schema_hash() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                pub field1: felt252,
+            }
+        };
+
+        let stale_args: proc_macro2::TokenStream = quote! { schema_hash = "0x00" };
+        assert!(check_schema_hash(stale_args, &input).is_err());
+    }
+
+    #[test]
+    fn test_attestation_abi_accepts_matching_schema_hash() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                pub field1: felt252,
+            }
+        };
+
+        let fields = match &input.data {
+            Data::Struct(data_struct) => match &data_struct.fields {
+                Fields::Named(fields_named) => &fields_named.named,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        let schema = canonical_schema_string("TestStruct", fields);
+        let hash = compute_schema_hash(&schema);
+
+        let args: proc_macro2::TokenStream = format!("schema_hash = \"{}\"", hash).parse().unwrap();
+        assert!(check_schema_hash(args, &input).is_ok());
+    }
+
+    #[test]
+    fn test_unit_enum_supported() {
         let input: DeriveInput = parse_quote! {
             pub enum TestEnum {
                 Variant1,
@@ -217,15 +1133,248 @@ mod tests {
         };
 
         let result = generate_abi_provider(&input);
-        assert!(result.is_err());
+        assert!(result.is_ok());
+
+        let generated = result.unwrap().to_string();
+        assert!(generated.contains("TestEnumABIProvider"));
+        assert!(generated.contains("Variant1"));
+        assert!(generated.contains("get_schema_hash"));
+    }
+
+    #[test]
+    fn test_enum_with_payload_supported() {
+        let input: DeriveInput = parse_quote! {
+            pub enum TestEnum {
+                A(felt252),
+                B(u64),
+            }
+        };
+
+        let result = generate_abi_provider(&input);
+        assert!(result.is_ok());
+
+        let generated = result.unwrap().to_string();
+        assert!(generated.contains("TestEnum :: A"));
+        assert!(generated.contains("deserialize_from_array"));
+    }
+
+    #[test]
+    fn test_nested_struct_field_resolved_at_runtime() {
+        let input: DeriveInput = parse_quote! {
+            pub struct Outer {
+                pub attester: ContractAddress,
+                pub payload: Inner,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("ABIProvider :: < Inner > :: get_abi"));
+        assert!(generated.contains("FieldDescriptor :: Nested"));
+        assert!(generated.contains("total_size += payload_abi . total_size"));
+    }
+
+    #[test]
+    fn test_from_calldata_generated_for_struct() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                pub attester: ContractAddress,
+                pub amount: u256,
+                pub active: bool,
+                pub note: ByteArray,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("fn from_calldata"));
+        assert!(generated.contains("CalldataParser :: parse_felt"));
+        assert!(generated.contains("CalldataParser :: parse_u256"));
+        assert!(generated.contains("CalldataParser :: parse_bool"));
+        assert!(generated.contains("CalldataParser :: parse_byte_array"));
+    }
+
+    #[test]
+    fn test_from_calldata_generated_for_enum() {
+        let input: DeriveInput = parse_quote! {
+            pub enum TestEnum {
+                A(felt252),
+                B,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("fn from_calldata"));
+        assert!(generated.contains("TestEnum :: A"));
+    }
+
+    #[test]
+    fn test_abi_json_generated_for_struct() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                pub attester: ContractAddress,
+                pub amount: u64,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("fn get_abi_json"));
+        assert!(generated.contains("TestStruct"));
+        assert!(generated.contains("schema_hash"));
+        assert!(generated.contains("attester"));
+        assert!(generated.contains("ContractAddress"));
+        assert!(generated.contains("size_bytes"));
+    }
+
+    #[test]
+    fn test_abi_json_includes_nested_field_at_runtime() {
+        let input: DeriveInput = parse_quote! {
+            pub struct Outer {
+                pub payload: Inner,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("ABIProvider :: < Inner > :: get_abi () . total_size"));
+    }
+
+    #[test]
+    fn test_abi_json_generated_for_enum() {
+        let input: DeriveInput = parse_quote! {
+            pub enum TestEnum {
+                A(felt252),
+                B,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("fn get_abi_json"));
+        assert!(generated.contains("TestEnum"));
+        assert!(generated.contains("felt252"));
+    }
+
+    #[test]
+    fn test_attestation_abi_forwards_name_and_version_via_attestation_meta() {
+        let input: DeriveInput = parse_quote! {
+            #[attestation_meta(name = "CustomName", version = "1.0")]
+            pub struct TestStruct {
+                pub field1: felt252,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("name : \"CustomName\""));
+        assert!(generated.contains("fn get_version () -> ByteArray { \"1.0\" }"));
+        assert!(generated.contains("\\\"name\\\":\\\"CustomName\\\""));
+    }
+
+    #[test]
+    fn test_attestation_meta_override_does_not_change_schema_hash() {
+        let plain: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                pub field1: felt252,
+            }
+        };
+        let renamed: DeriveInput = parse_quote! {
+            #[attestation_meta(name = "CustomName", version = "1.0")]
+            pub struct TestStruct {
+                pub field1: felt252,
+            }
+        };
+
+        let plain_generated = generate_abi_provider(&plain).unwrap().to_string();
+        let renamed_generated = generate_abi_provider(&renamed).unwrap().to_string();
+
+        let extract_hash = |generated: &str| {
+            generated
+                .split("fn get_schema_hash () -> felt252 {")
+                .nth(1)
+                .unwrap()
+                .split('}')
+                .next()
+                .unwrap()
+                .trim()
+                .to_string()
+        };
+        assert_eq!(
+            extract_hash(&plain_generated),
+            extract_hash(&renamed_generated)
+        );
+    }
+
+    #[test]
+    fn test_attestation_meta_defaults_to_struct_name_and_empty_version() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                pub field1: felt252,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("name : \"TestStruct\""));
+        assert!(generated.contains("fn get_version () -> ByteArray { \"\" }"));
+    }
+
+    #[test]
+    fn test_abi_field_description_threaded_into_abi_and_json() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                #[abi_field(description = "The attester address")]
+                pub attester: ContractAddress,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("description : \"The attester address\""));
+        assert!(generated.contains("The attester address"));
+    }
+
+    #[test]
+    fn test_abi_field_description_defaults_to_empty_string() {
+        let input: DeriveInput = parse_quote! {
+            pub struct TestStruct {
+                pub attester: ContractAddress,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("description : \"\""));
+    }
+
+    #[test]
+    fn test_enum_variant_description_and_meta_override() {
+        let input: DeriveInput = parse_quote! {
+            #[attestation_meta(name = "CustomEnum")]
+            pub enum TestEnum {
+                #[abi_field(description = "A variant")]
+                A(felt252),
+                B,
+            }
+        };
+
+        let generated = generate_abi_provider(&input).unwrap().to_string();
+        assert!(generated.contains("name : \"CustomEnum\""));
+        assert!(generated.contains("description : \"A variant\""));
+        assert!(generated.contains("\\\"name\\\":\\\"CustomEnum\\\""));
     }
 
     #[test]
     fn test_type_mapping() {
-        assert_eq!(get_type_info(&parse_quote!(ContractAddress)), ("ContractAddress", 32));
-        assert_eq!(get_type_info(&parse_quote!(felt252)), ("felt252", 32));
-        assert_eq!(get_type_info(&parse_quote!(u64)), ("u64", 8));
-        assert_eq!(get_type_info(&parse_quote!(bool)), ("bool", 1));
-        assert_eq!(get_type_info(&parse_quote!(ByteArray)), ("ByteArray", 0));
+        assert_eq!(
+            get_type_info(&parse_quote!(ContractAddress)),
+            ("ContractAddress".to_string(), 32)
+        );
+        assert_eq!(
+            get_type_info(&parse_quote!(felt252)),
+            ("felt252".to_string(), 32)
+        );
+        assert_eq!(get_type_info(&parse_quote!(u64)), ("u64".to_string(), 8));
+        assert_eq!(get_type_info(&parse_quote!(bool)), ("bool".to_string(), 1));
+        assert_eq!(
+            get_type_info(&parse_quote!(ByteArray)),
+            ("ByteArray".to_string(), 0)
+        );
+        assert_eq!(
+            get_type_info(&parse_quote!(Inner)),
+            ("Inner".to_string(), 0)
+        );
     }
 }
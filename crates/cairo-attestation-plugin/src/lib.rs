@@ -1,7 +1,12 @@
 //! Cairo Plugin for Automatic ABIProvider Generation
 //!
 //! This plugin provides a `#[derive(Attestation)]` attribute that automatically generates
-//! ABIProvider trait implementations for Cairo structs.
+//! ABIProvider trait implementations for Cairo structs and enums, including serialization,
+//! deserialization, human-readable calldata parsing, and a machine-readable JSON ABI
+//! artifact for off-chain tooling. Fields (or variant payloads) whose type is itself a
+//! `#[derive(Attestation)]` struct or enum are resolved recursively via that type's own
+//! `ABIProvider`. `#[attestation_abi(name = "...", version = "...")]` and `#[abi_field(description
+//! = "...")]` attach display metadata to the generated ABI without affecting the schema hash.
 
 use std::sync::Arc;
 
@@ -10,11 +15,13 @@ use cairo_lang_defs::plugin::{
 };
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_syntax::node::ast::{
-    Attribute, Item, ItemStruct, Member, MemberList, StructArgList, TypeClause,
+    Attribute, AttributeList, Item, ItemEnum, ItemStruct, Member, MemberList, OptionTypeClause,
+    StructArgList, TypeClause, Variant, VariantList,
 };
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
 use indoc::formatdoc;
+use sha3::{Digest, Keccak256};
 
 /// The main plugin implementation
 #[derive(Debug, Default)]
@@ -52,12 +59,40 @@ impl MacroPlugin for AttestationPlugin {
                     },
                 }
             }
+            cairo_lang_syntax::node::ast::ModuleItem::Enum(enum_ast) => {
+                // Check if the enum has the Attestation derive attribute
+                if !has_attestation_derive_enum(db, &enum_ast) {
+                    return PluginResult::default();
+                }
+
+                match generate_abi_provider_for_enum(db, &enum_ast) {
+                    Ok(code) => PluginResult {
+                        code: Some(PluginGeneratedFile {
+                            name: format!("{}_abi_provider.cairo", enum_ast.name(db).text(db)),
+                            content: code,
+                            code_mappings: vec![],
+                            aux_data: None,
+                        }),
+                        diagnostics: vec![],
+                        remove_original_item: false,
+                    },
+                    Err(diagnostic) => PluginResult {
+                        code: None,
+                        diagnostics: vec![diagnostic],
+                        remove_original_item: false,
+                    },
+                }
+            }
             _ => PluginResult::default(),
         }
     }
 
     fn declared_attributes(&self) -> Vec<String> {
-        vec!["derive".to_string()]
+        vec![
+            "derive".to_string(),
+            "attestation_abi".to_string(),
+            "abi_field".to_string(),
+        ]
     }
 }
 
@@ -75,6 +110,20 @@ fn has_attestation_derive(struct_ast: &ItemStruct) -> bool {
     false
 }
 
+/// Check if an enum has the Attestation derive attribute
+fn has_attestation_derive_enum(db: &dyn SyntaxGroup, enum_ast: &ItemEnum) -> bool {
+    for attr in enum_ast.attributes(db).elements(db).iter() {
+        if let Some(attr_list) = get_derive_attr_list(db, attr) {
+            for derive_input in attr_list.iter() {
+                if derive_input.as_syntax_node().get_text_without_trivia(db) == "Attestation" {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
 /// Extract derive attribute list from an attribute
 fn get_derive_attr_list(
     db: &dyn SyntaxGroup,
@@ -106,6 +155,15 @@ fn generate_abi_provider_for_struct(
     let struct_name = struct_ast.name(db).text(db);
     let struct_name_str = struct_name.clone();
 
+    // `#[attestation_abi(name = "...", version = "...")]` overrides the
+    // display name reported by `get_abi()` and the string `get_version()`
+    // returns; the schema hash itself still hashes the struct as declared,
+    // so a cosmetic rename can't silently change the on-chain layout.
+    let display_name = get_attestation_abi_arg(db, &struct_ast.attributes(db), "name")
+        .unwrap_or_else(|| struct_name_str.clone());
+    let version =
+        get_attestation_abi_arg(db, &struct_ast.attributes(db), "version").unwrap_or_default();
+
     // Get struct members
     let members = match struct_ast.members(db) {
         MemberList::MemberList(member_list) => member_list.elements(db),
@@ -119,8 +177,17 @@ fn generate_abi_provider_for_struct(
     };
 
     let mut field_definitions = Vec::new();
-    let mut total_size = 0u32;
+    let mut schema_parts = Vec::new();
+    let mut field_names = Vec::new();
+    let mut field_deserializers = Vec::new();
+    let mut field_calldata_parsers = Vec::new();
+    let mut json_field_entries = Vec::new();
     let field_count = members.len();
+    // `Array<T>`, `Span<T>`, and nested attestation types aren't
+    // representable as a single calldata literal, so if any field has one
+    // of those types `from_calldata` bails instead of leaving an unbound
+    // field in the struct constructor below.
+    let mut all_fields_calldata_literal = true;
 
     for member in members.iter() {
         if let Member::Member(member_ast) = member {
@@ -128,19 +195,329 @@ fn generate_abi_provider_for_struct(
             let field_type = member_ast.type_clause(db);
 
             let (type_name, size_bytes) = get_cairo_type_info(db, &field_type);
-            total_size += size_bytes;
+            let description = get_abi_field_arg(db, &member_ast.attributes(db), "description")
+                .unwrap_or_default();
+
+            field_definitions.push(if is_primitive_type_name(&type_name) {
+                formatdoc! {r#"
+                    total_size += {size_bytes};
+                    fields.append(ABIField {{
+                        name: "{field_name}",
+                        field_type: "{type_name}",
+                        size_bytes: {size_bytes},
+                        description: "{description}",
+                        descriptor: FieldDescriptor::None,
+                    }});
+                "#}
+            } else {
+                // Not a recognized primitive: treat it as a nested
+                // `#[derive(Attestation)]` type and resolve its ABI (and
+                // therefore its size) at runtime.
+                formatdoc! {r#"
+                    let {field_name}_abi = ABIProvider::<{type_name}>::get_abi();
+                    total_size += {field_name}_abi.total_size;
+                    fields.append(ABIField {{
+                        name: "{field_name}",
+                        field_type: "{type_name}",
+                        size_bytes: {field_name}_abi.total_size,
+                        description: "{description}",
+                        descriptor: FieldDescriptor::Nested({field_name}_abi),
+                    }});
+                "#}
+            });
+            schema_parts.push(format!("{}:{}", field_name, type_name));
+            if !is_calldata_literal_type(&type_name) {
+                all_fields_calldata_literal = false;
+            }
+
+            field_deserializers.push(generate_field_deserializer_code(&field_name, &type_name));
+            field_calldata_parsers
+                .push(generate_field_calldata_parser_code(&field_name, &type_name));
+            json_field_entries.push(generate_field_json_entry_code(
+                &field_name,
+                &type_name,
+                size_bytes,
+                &description,
+            ));
+            field_names.push(field_name.to_string());
+        }
+    }
+
+    let field_definitions_code = field_definitions.join("\n            ");
+    let field_deserializers_code = field_deserializers.join("\n");
+    let json_field_entries_code = json_field_entries.join("\n                json += \",\";\n");
+    let struct_literal_fields = field_names.join(", ");
+
+    let from_calldata_body = if all_fields_calldata_literal {
+        let field_calldata_parsers_code = field_calldata_parsers.join("\n");
+        format!(
+            "{field_calldata_parsers_code}\n\n                Option::Some({struct_name} {{ {struct_literal_fields} }})"
+        )
+    } else {
+        // At least one field (`Array<T>`/`Span<T>`/a nested attestation
+        // type) isn't representable as a single human-readable literal, so
+        // calldata construction isn't supported for this type.
+        "let _ = args;\n                Option::None".to_string()
+    };
+
+    let schema = format!("{}({})", struct_name_str, schema_parts.join(","));
+    let schema_hash = compute_schema_hash(&schema);
+    let display_name_json = json_escape(&display_name);
+    let version_json = json_escape(&version);
+
+    if let Some(declared_hash) =
+        get_attestation_abi_arg(db, &struct_ast.attributes(db), "schema_hash")
+    {
+        if !declared_hash.eq_ignore_ascii_case(&schema_hash) {
+            return Err(PluginDiagnostic {
+                stable_ptr: struct_ast.stable_ptr().untyped(),
+                message: format!(
+                    "attestation_abi schema_hash mismatch: declared {} but `{}` now hashes to {} \
+                     (a field was added, removed, reordered, or retyped)",
+                    declared_hash, struct_name_str, schema_hash
+                ),
+                severity: Severity::Error,
+            });
+        }
+    }
+
+    let generated_code = formatdoc! {r#"
+        /// Auto-generated ABIProvider implementation for {}
+        impl {}ABIProvider of ABIProvider<{}> {{
+            fn get_abi() -> StructABI {{
+                let mut fields = array![];
+                let mut total_size: u32 = 0;
+
+                {}
+
+                StructABI {{
+                    name: "{}",
+                    fields,
+                    total_size,
+                }}
+            }}
+
+            fn get_version() -> ByteArray {{
+                "{}"
+            }}
+
+            fn get_field_count() -> u32 {{
+                {}
+            }}
+
+            fn serialize_to_array(self: {}) -> Array<felt252> {{
+                let mut serialized = array![];
+                self.serialize(ref serialized);
+                serialized
+            }}
+
+            fn deserialize_from_array(ref span: Span<felt252>) -> Option<{}> {{
+                {}
 
-            field_definitions.push(formatdoc! {r#"
+                Option::Some({} {{ {} }})
+            }}
+
+            fn get_schema_hash() -> felt252 {{
+                {}
+            }}
+
+            fn from_calldata(mut args: Span<ByteArray>) -> Option<{}> {{
+                {}
+            }}
+
+            fn get_abi_json() -> ByteArray {{
+                let mut json: ByteArray = "{{\"name\":\"{}\",\"schema_hash\":\"{}\",\"version\":\"{}\",\"fields\":[";
+
+                {}
+
+                json += format!("],\"total_size\":{{}}}}}}", Self::get_abi().total_size);
+                json
+            }}
+        }}
+    "#,
+        struct_name_str,
+        struct_name,
+        struct_name,
+        field_definitions_code,
+        display_name,
+        version,
+        field_count,
+        struct_name,
+        struct_name,
+        field_deserializers_code,
+        struct_name,
+        struct_literal_fields,
+        schema_hash,
+        struct_name,
+        from_calldata_body,
+        display_name_json,
+        schema_hash,
+        version_json,
+        json_field_entries_code
+    };
+
+    Ok(generated_code)
+}
+
+/// Generate ABIProvider implementation for an enum: a one-felt discriminant
+/// followed by the matching variant's payload. Variants may carry no payload
+/// or exactly one. When every variant's payload is the same size the layout
+/// has a fixed `total_size`; otherwise it's reported as variable (`0`), the
+/// same convention `ByteArray`/`Array`/`Span` already use.
+fn generate_abi_provider_for_enum(
+    db: &dyn SyntaxGroup,
+    enum_ast: &ItemEnum,
+) -> Result<String, PluginDiagnostic> {
+    const DISCRIMINANT_SIZE: u32 = 32;
+
+    let enum_name = enum_ast.name(db).text(db);
+    let enum_name_str = enum_name.clone();
+
+    // See `generate_abi_provider_for_struct`: the display name/version are
+    // cosmetic overrides and never affect the schema hash below.
+    let display_name = get_attestation_abi_arg(db, &enum_ast.attributes(db), "name")
+        .unwrap_or_else(|| enum_name_str.clone());
+    let version =
+        get_attestation_abi_arg(db, &enum_ast.attributes(db), "version").unwrap_or_default();
+
+    let variants = match enum_ast.variants(db) {
+        VariantList::VariantList(variant_list) => variant_list.elements(db),
+        _ => {
+            return Err(PluginDiagnostic {
+                stable_ptr: enum_ast.stable_ptr().untyped(),
+                message: "Only enums with named variants are supported".to_string(),
+                severity: Severity::Error,
+            });
+        }
+    };
+
+    let mut variant_definitions = Vec::new();
+    let mut variant_arms = Vec::new();
+    let mut variant_calldata_arms = Vec::new();
+    let mut schema_parts = Vec::new();
+    let mut variant_sizes = Vec::new();
+    let mut variant_descriptions = Vec::new();
+    let mut has_variable_payload = false;
+    let field_count = variants.len();
+
+    for (index, variant) in variants.iter().enumerate() {
+        if let Variant::Variant(variant_ast) = variant {
+            let variant_name = variant_ast.name(db).text(db);
+
+            let (payload_type, payload_size) = match variant_ast.type_clause(db) {
+                OptionTypeClause::TypeClause(type_clause) => get_cairo_type_info(db, &type_clause),
+                OptionTypeClause::Empty(_) => ("()".to_string(), 0u32),
+            };
+
+            schema_parts.push(format!("{}:{}", variant_name, payload_type));
+            variant_sizes.push(payload_size);
+            // A payload reported as size `0` is variable (or a nested type
+            // whose size is only known at runtime), not a fixed zero-byte one.
+            if payload_type != "()" && payload_size == 0 {
+                has_variable_payload = true;
+            }
+            let description = get_abi_field_arg(db, &variant_ast.attributes(db), "description")
+                .unwrap_or_default();
+            variant_descriptions.push(description.clone());
+
+            variant_definitions.push(formatdoc! {r#"
                 fields.append(ABIField {{
                     name: "{}",
                     field_type: "{}",
                     size_bytes: {},
+                    description: "{}",
+                    descriptor: FieldDescriptor::None,
                 }});
-            "#, field_name, type_name, size_bytes});
+            "#, variant_name, payload_type, payload_size, description});
+
+            variant_arms.push(match variant_ast.type_clause(db) {
+                OptionTypeClause::Empty(_) => {
+                    format!("{} => Option::Some({}::{}),", index, enum_name, variant_name)
+                }
+                OptionTypeClause::TypeClause(_) => formatdoc! {r#"
+                    {} => {{
+                        {}
+                        Option::Some({}::{}(payload))
+                    }},
+                "#, index, generate_field_deserializer_code("payload", &payload_type), enum_name, variant_name},
+            });
+
+            variant_calldata_arms.push(match variant_ast.type_clause(db) {
+                OptionTypeClause::Empty(_) => {
+                    format!("{} => Option::Some({}::{}),", index, enum_name, variant_name)
+                }
+                OptionTypeClause::TypeClause(_) if is_calldata_literal_type(&payload_type) => {
+                    formatdoc! {r#"
+                        {} => {{
+                            {}
+                            Option::Some({}::{}(payload))
+                        }},
+                    "#, index, generate_field_calldata_parser_code("payload", &payload_type), enum_name, variant_name}
+                }
+                // `Array<T>`/`Span<T>`/a nested attestation type isn't
+                // representable as a single literal, so this variant can't
+                // be constructed from calldata.
+                OptionTypeClause::TypeClause(_) => {
+                    format!("{} => Option::None,", index)
+                }
+            });
         }
     }
 
-    let field_definitions_code = field_definitions.join("\n            ");
+    let variant_definitions_code = variant_definitions.join("\n            ");
+    let variant_arms_code = variant_arms.join("\n            ");
+    let variant_calldata_arms_code = variant_calldata_arms.join("\n            ");
+
+    let total_size = if !has_variable_payload
+        && variant_sizes.windows(2).all(|pair| pair[0] == pair[1])
+    {
+        DISCRIMINANT_SIZE + variant_sizes.first().copied().unwrap_or(0)
+    } else {
+        0
+    };
+
+    let schema = format!("{}({})", enum_name_str, schema_parts.join(","));
+    let schema_hash = compute_schema_hash(&schema);
+
+    if let Some(declared_hash) =
+        get_attestation_abi_arg(db, &enum_ast.attributes(db), "schema_hash")
+    {
+        if !declared_hash.eq_ignore_ascii_case(&schema_hash) {
+            return Err(PluginDiagnostic {
+                stable_ptr: enum_ast.stable_ptr().untyped(),
+                message: format!(
+                    "attestation_abi schema_hash mismatch: declared {} but computed {}. \
+                     The enum's variants changed since the hash was frozen.",
+                    declared_hash, schema_hash
+                ),
+                severity: Severity::Error,
+            });
+        }
+    }
+
+    // Every variant's name/payload-type/size is already known at this point,
+    // so the JSON ABI artifact can be baked in as a single literal (unlike
+    // the struct path, which may defer nested field sizes to runtime).
+    let json_fields = schema_parts
+        .iter()
+        .zip(variant_sizes.iter())
+        .zip(variant_descriptions.iter())
+        .map(|((schema_part, size_bytes), description)| {
+            let (name, field_type) = schema_part.split_once(':').unwrap();
+            let description = json_escape(description);
+            format!(
+                r#"{{\"name\":\"{}\",\"type\":\"{}\",\"size_bytes\":{},\"description\":\"{}\"}}"#,
+                name, field_type, size_bytes, description
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let display_name_json = json_escape(&display_name);
+    let version_json = json_escape(&version);
+    let abi_json = format!(
+        r#"{{\"name\":\"{}\",\"schema_hash\":\"{}\",\"version\":\"{}\",\"fields\":[{}],\"total_size\":{}}}"#,
+        display_name_json, schema_hash, version_json, json_fields, total_size
+    );
 
     let generated_code = formatdoc! {r#"
         /// Auto-generated ABIProvider implementation for {}
@@ -157,6 +534,10 @@ fn generate_abi_provider_for_struct(
                 }}
             }}
 
+            fn get_version() -> ByteArray {{
+                "{}"
+            }}
+
             fn get_field_count() -> u32 {{
                 {}
             }}
@@ -166,24 +547,362 @@ fn generate_abi_provider_for_struct(
                 self.serialize(ref serialized);
                 serialized
             }}
+
+            fn deserialize_from_array(ref span: Span<felt252>) -> Option<{}> {{
+                let discriminant = match span.pop_front() {{
+                    Option::Some(value) => *value,
+                    Option::None => {{ return Option::None; }},
+                }};
+                // `felt252` only allows `0`/`_` match patterns, so the
+                // discriminant is converted to `u32` before dispatch.
+                let discriminant: u32 = match discriminant.try_into() {{
+                    Option::Some(converted) => converted,
+                    Option::None => {{ return Option::None; }},
+                }};
+
+                match discriminant {{
+                    {}
+                    _ => Option::None,
+                }}
+            }}
+
+            fn get_schema_hash() -> felt252 {{
+                {}
+            }}
+
+            fn from_calldata(mut args: Span<ByteArray>) -> Option<{}> {{
+                let discriminant_arg = match args.pop_front() {{
+                    Option::Some(value) => value,
+                    Option::None => {{ return Option::None; }},
+                }};
+                let discriminant: u32 = match CalldataParser::parse_felt(discriminant_arg) {{
+                    Option::Some(value) => match value.try_into() {{
+                        Option::Some(converted) => converted,
+                        Option::None => {{ return Option::None; }},
+                    }},
+                    Option::None => {{ return Option::None; }},
+                }};
+
+                match discriminant {{
+                    {}
+                    _ => Option::None,
+                }}
+            }}
+
+            fn get_abi_json() -> ByteArray {{
+                "{}"
+            }}
         }}
     "#,
-        struct_name_str,
-        struct_name,
-        struct_name,
-        field_definitions_code,
-        struct_name_str,
+        enum_name_str,
+        enum_name,
+        enum_name,
+        variant_definitions_code,
+        display_name,
         total_size,
+        version,
         field_count,
-        struct_name
+        enum_name,
+        enum_name,
+        variant_arms_code,
+        schema_hash,
+        enum_name,
+        variant_calldata_arms_code,
+        abi_json
     };
 
     Ok(generated_code)
 }
 
+/// Whether `type_name` is one of the scalar/variable-size primitives
+/// `get_cairo_type_info` recognizes, as opposed to a nested attestation type.
+fn is_primitive_type_name(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "ContractAddress"
+            | "felt252"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "u256"
+            | "bool"
+            | "ByteArray"
+            | "Array"
+            | "Span"
+    )
+}
+
+/// Whether `type_name` can be parsed from a single human-readable calldata
+/// argument by `generate_field_calldata_parser_code`. Unlike
+/// `is_primitive_type_name`, this excludes `Array`/`Span` (and, by not
+/// matching, nested attestation types): they consume a variable number of
+/// argument strings rather than exactly one, so they aren't representable
+/// as a single literal.
+fn is_calldata_literal_type(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "ContractAddress"
+            | "felt252"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "u256"
+            | "bool"
+            | "ByteArray"
+    )
+}
+
+/// Hash a canonical schema string the same way `starknet_keccak` does: a
+/// keccak256 digest of the UTF-8 bytes, masked down to 250 bits so it fits in
+/// a `felt252`. Returned as a `0x`-prefixed hex literal. Mirrors
+/// `compute_schema_hash` in the `attestation-derive` crate so both code-gen
+/// paths agree on the digest.
+fn compute_schema_hash(schema: &str) -> String {
+    let mut hasher = Keccak256::new();
+    hasher.update(schema.as_bytes());
+    let mut digest = hasher.finalize();
+
+    // Clear the top 6 bits (256 - 250) so the value fits in a felt252.
+    digest[0] &= 0x03;
+
+    let hex: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("0x{}", hex)
+}
+
+/// Escape `"` and `\` (and control characters that would otherwise break a
+/// JSON string literal) in a user-supplied value — a `description`, `name`,
+/// or `version` from `attestation_abi`/`abi_field` — before it's embedded in
+/// the `get_abi_json()` output.
+fn json_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Pull a `key = "value"` string argument out of `#[attestation_abi(...)]`,
+/// if `attributes` carries that attribute and the key is present.
+fn get_attestation_abi_arg(
+    db: &dyn SyntaxGroup,
+    attributes: &AttributeList,
+    key: &str,
+) -> Option<String> {
+    for attr in attributes.elements(db).iter() {
+        if attr.attr(db).text(db) != "attestation_abi" {
+            continue;
+        }
+
+        let args = attr.arguments(db)?;
+        let arg_list = args.arg_list(db)?;
+        for elem in arg_list.elements(db).iter() {
+            let text = elem.as_syntax_node().get_text_without_trivia(db);
+            let (name, value) = text.split_once('=')?;
+            if name.trim() == key {
+                return Some(value.trim().trim_matches('"').to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Pull a `key = "value"` string argument out of an `#[abi_field(...)]`
+/// attribute attached to a struct member or enum variant, if present.
+fn get_abi_field_arg(
+    db: &dyn SyntaxGroup,
+    attributes: &AttributeList,
+    key: &str,
+) -> Option<String> {
+    for attr in attributes.elements(db).iter() {
+        if attr.attr(db).text(db) != "abi_field" {
+            continue;
+        }
+
+        let args = attr.arguments(db)?;
+        let arg_list = args.arg_list(db)?;
+        for elem in arg_list.elements(db).iter() {
+            let text = elem.as_syntax_node().get_text_without_trivia(db);
+            let (name, value) = text.split_once('=')?;
+            if name.trim() == key {
+                return Some(value.trim().trim_matches('"').to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Generate the buffer-reader snippet that reconstructs a single field named
+/// `field_name` of Cairo type `type_name` from the front of `span`. Mirrors
+/// `generate_field_deserializer` in the `attestation-derive` crate so both
+/// code-gen paths agree on the wire format.
+fn generate_field_deserializer_code(field_name: &str, type_name: &str) -> String {
+    match type_name {
+        "felt252" => formatdoc! {r#"
+            let {field_name} = match span.pop_front() {{
+                Option::Some(value) => *value,
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        "ContractAddress" | "u8" | "u16" | "u32" | "u64" | "u128" => formatdoc! {r#"
+            let {field_name} = match span.pop_front() {{
+                Option::Some(value) => match (*value).try_into() {{
+                    Option::Some(converted) => converted,
+                    Option::None => {{ return Option::None; }},
+                }},
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        "bool" => formatdoc! {r#"
+            let {field_name} = match span.pop_front() {{
+                Option::Some(value) => if *value == 0 {{
+                    false
+                }} else if *value == 1 {{
+                    true
+                }} else {{
+                    return Option::None;
+                }},
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        "u256" => formatdoc! {r#"
+            let {field_name} = {{
+                let low = match span.pop_front() {{
+                    Option::Some(value) => *value,
+                    Option::None => {{ return Option::None; }},
+                }};
+                let high = match span.pop_front() {{
+                    Option::Some(value) => *value,
+                    Option::None => {{ return Option::None; }},
+                }};
+                u256 {{
+                    low: match low.try_into() {{
+                        Option::Some(converted) => converted,
+                        Option::None => {{ return Option::None; }},
+                    }},
+                    high: match high.try_into() {{
+                        Option::Some(converted) => converted,
+                        Option::None => {{ return Option::None; }},
+                    }},
+                }}
+            }};"#},
+        // ByteArray, Array, Span: defer to the type's own Serde impl.
+        "ByteArray" | "Array" | "Span" => formatdoc! {r#"
+            let {field_name} = match Serde::deserialize(ref span) {{
+                Option::Some(value) => value,
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        // Anything else is a nested `#[derive(Attestation)]` type: recurse
+        // into its own generated deserializer.
+        _ => formatdoc! {r#"
+            let {field_name} = match ABIProvider::<{type_name}>::deserialize_from_array(ref span) {{
+                Option::Some(value) => value,
+                Option::None => {{ return Option::None; }},
+            }};"#},
+    }
+}
+
+/// Generate the snippet that parses a single field named `field_name` of
+/// Cairo type `type_name` out of the next human-readable calldata argument
+/// (a `ByteArray`, e.g. `"0x1"`, `"42"`, `"true"`, or `"'hello'"`). Mirrors
+/// `generate_field_calldata_parser` in the `attestation-derive` crate so both
+/// code-gen paths infer literal formats the same way `sncast`'s
+/// `TryInferFormat` does. `Array`/`Span`/nested fields are not yet supported
+/// from calldata and bail out with `Option::None`.
+fn generate_field_calldata_parser_code(field_name: &str, type_name: &str) -> String {
+    let pop_arg = formatdoc! {r#"
+        let {field_name}_arg = match args.pop_front() {{
+            Option::Some(value) => value,
+            Option::None => {{ return Option::None; }},
+        }};"#};
+
+    let parse = match type_name {
+        "felt252" => formatdoc! {r#"
+            let {field_name} = match CalldataParser::parse_felt({field_name}_arg) {{
+                Option::Some(value) => value,
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        "bool" => formatdoc! {r#"
+            let {field_name} = match CalldataParser::parse_bool({field_name}_arg) {{
+                Option::Some(value) => value,
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        "ContractAddress" | "u8" | "u16" | "u32" | "u64" => formatdoc! {r#"
+            let {field_name} = match CalldataParser::parse_felt({field_name}_arg) {{
+                Option::Some(value) => match value.try_into() {{
+                    Option::Some(converted) => converted,
+                    Option::None => {{ return Option::None; }},
+                }},
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        "u128" => formatdoc! {r#"
+            let {field_name} = match CalldataParser::parse_u256({field_name}_arg) {{
+                Option::Some(value) => match value.try_into() {{
+                    Option::Some(converted) => converted,
+                    Option::None => {{ return Option::None; }},
+                }},
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        "u256" => formatdoc! {r#"
+            let {field_name} = match CalldataParser::parse_u256({field_name}_arg) {{
+                Option::Some(value) => value,
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        "ByteArray" => formatdoc! {r#"
+            let {field_name} = match CalldataParser::parse_byte_array({field_name}_arg) {{
+                Option::Some(value) => value,
+                Option::None => {{ return Option::None; }},
+            }};"#},
+        // Array/Span/nested fields aren't representable as a single
+        // human-readable literal yet.
+        _ => "return Option::None;".to_string(),
+    };
+
+    format!("{}\n{}", pop_arg, parse)
+}
+
+/// Generate the `get_abi_json()` fragment for a single field: a JSON object
+/// literal with `name`, `type`, `size_bytes`, and `description` keys,
+/// appended to a `json: ByteArray` accumulator. Mirrors
+/// `generate_field_json_entry` in the `attestation-derive` crate: primitive
+/// fields are baked in as a literal; nested fields interpolate their
+/// runtime-resolved size.
+fn generate_field_json_entry_code(
+    field_name: &str,
+    type_name: &str,
+    size_bytes: u32,
+    description: &str,
+) -> String {
+    let description = json_escape(description);
+    if is_primitive_type_name(type_name) {
+        format!(
+            r#"json += "{{\"name\":\"{}\",\"type\":\"{}\",\"size_bytes\":{},\"description\":\"{}\"}}";"#,
+            field_name, type_name, size_bytes, description
+        )
+    } else {
+        formatdoc! {r#"
+            let {}_json_size = ABIProvider::<{}>::get_abi().total_size;
+            json += format!(
+                "{{{{\"name\":\"{}\",\"type\":\"{}\",\"size_bytes\":{{}},\"description\":\"{}\"}}}}",
+                {}_json_size,
+            );
+        "#, field_name, type_name, field_name, type_name, description, field_name}
+    }
+}
+
 /// Map Cairo types to their string representation and byte size
 fn get_cairo_type_info(db: &dyn SyntaxGroup, type_clause: &TypeClause) -> (String, u32) {
-    let type_text = type_clause.ty(db).as_syntax_node().get_text_without_trivia(db);
+    let type_text = type_clause
+        .ty(db)
+        .as_syntax_node()
+        .get_text_without_trivia(db);
 
     match type_text.as_str() {
         "ContractAddress" => ("ContractAddress".to_string(), 32),
@@ -198,7 +917,7 @@ fn get_cairo_type_info(db: &dyn SyntaxGroup, type_clause: &TypeClause) -> (Strin
         "ByteArray" => ("ByteArray".to_string(), 0), // Variable size
         _ if type_text.starts_with("Array<") => ("Array".to_string(), 0), // Variable size
         _ if type_text.starts_with("Span<") => ("Span".to_string(), 0), // Variable size
-        _ => (type_text, 0), // Unknown types default to 0 size
+        _ => (type_text, 0),                         // Unknown types default to 0 size
     }
 }
 
@@ -272,4 +991,98 @@ mod tests {
             assert_eq!(result, expected);
         }
     }
+
+    #[test]
+    fn test_field_deserializer_scalar() {
+        let code = generate_field_deserializer_code("field1", "felt252");
+        assert!(code.contains("pop_front"));
+        assert!(code.contains("field1"));
+    }
+
+    #[test]
+    fn test_field_deserializer_wide_integer() {
+        let code = generate_field_deserializer_code("field2", "u256");
+        assert!(code.contains("low"));
+        assert!(code.contains("high"));
+    }
+
+    #[test]
+    fn test_field_deserializer_variable_size() {
+        let code = generate_field_deserializer_code("field3", "ByteArray");
+        assert!(code.contains("Serde::deserialize"));
+    }
+
+    #[test]
+    fn test_field_deserializer_nested_type() {
+        let code = generate_field_deserializer_code("payload", "Inner");
+        assert!(code.contains("ABIProvider::<Inner>::deserialize_from_array"));
+    }
+
+    #[test]
+    fn test_is_primitive_type_name() {
+        assert!(is_primitive_type_name("felt252"));
+        assert!(is_primitive_type_name("ByteArray"));
+        assert!(!is_primitive_type_name("Inner"));
+    }
+
+    #[test]
+    fn test_calldata_parser_scalar() {
+        let code = generate_field_calldata_parser_code("attester", "ContractAddress");
+        assert!(code.contains("CalldataParser::parse_felt"));
+        assert!(code.contains("try_into"));
+    }
+
+    #[test]
+    fn test_calldata_parser_bool() {
+        let code = generate_field_calldata_parser_code("active", "bool");
+        assert!(code.contains("CalldataParser::parse_bool"));
+        assert!(!code.contains("try_into"));
+    }
+
+    #[test]
+    fn test_calldata_parser_wide_integer() {
+        let code = generate_field_calldata_parser_code("amount", "u256");
+        assert!(code.contains("CalldataParser::parse_u256"));
+    }
+
+    #[test]
+    fn test_calldata_parser_byte_array() {
+        let code = generate_field_calldata_parser_code("note", "ByteArray");
+        assert!(code.contains("CalldataParser::parse_byte_array"));
+    }
+
+    #[test]
+    fn test_calldata_parser_rejects_unsupported_type() {
+        let code = generate_field_calldata_parser_code("items", "Array");
+        assert!(code.contains("Option::None"));
+        assert!(!code.contains("CalldataParser::"));
+    }
+
+    #[test]
+    fn test_schema_hash_deterministic_and_sensitive_to_fields() {
+        let a = compute_schema_hash("TestStruct(field1:felt252)");
+        let b = compute_schema_hash("TestStruct(field1:felt252)");
+        let c = compute_schema_hash("TestStruct(field1:felt252,field2:u64)");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a.starts_with("0x"));
+    }
+
+    #[test]
+    fn test_json_entry_scalar() {
+        let code =
+            generate_field_json_entry_code("attester", "ContractAddress", 32, "The attester");
+        assert!(code.contains(r#"\"name\":\"attester\""#));
+        assert!(code.contains(r#"\"type\":\"ContractAddress\""#));
+        assert!(code.contains(r#"\"size_bytes\":32"#));
+        assert!(code.contains(r#"\"description\":\"The attester\""#));
+    }
+
+    #[test]
+    fn test_json_entry_nested_type() {
+        let code = generate_field_json_entry_code("payload", "Inner", 0, "The payload");
+        assert!(code.contains("ABIProvider::<Inner>::get_abi().total_size"));
+        assert!(code.contains(r#"\"type\":\"Inner\""#));
+        assert!(code.contains(r#"\"description\":\"The payload\""#));
+    }
 }